@@ -11,6 +11,7 @@ use core::ptr::NonNull;
 #[derive(Debug)]
 pub(crate) struct LlistNode {
     pub next: Option<NonNull<LlistNode>>,
+    pub prev: Option<NonNull<LlistNode>>,
     pub next_of_prev: *mut Option<NonNull<LlistNode>>,
 }
 
@@ -30,20 +31,23 @@ impl LlistNode {
     /// # Safety
     /// * `node` must be `ptr::write`-able.
     /// * `next_of_prev` must be dereferencable and valid.
+    /// * `prev` must be the node (if any) whose `next` field `next_of_prev` addresses.
     pub unsafe fn insert(
         node: *mut Self,
         next_of_prev: *mut Option<NonNull<LlistNode>>,
+        prev: Option<NonNull<LlistNode>>,
         next: Option<NonNull<LlistNode>>,
     ) {
         debug_assert!(node > 0x1000 as _);
         debug_assert!(next_of_prev > 0x1000 as _);
 
-        node.write(Self { next_of_prev, next });
+        node.write(Self { next_of_prev, prev, next });
 
         *next_of_prev = Some(NonNull::new_unchecked(node));
 
         if let Some(next) = next {
             (*next.as_ptr()).next_of_prev = Self::next_ptr(node);
+            (*next.as_ptr()).prev = Some(NonNull::new_unchecked(node));
         }
     }
 
@@ -55,7 +59,7 @@ impl LlistNode {
     /// * `self` must be dereferencable and valid.
     pub unsafe fn remove(node: *mut Self) {
         debug_assert!(node > 0x1000 as _);
-        let LlistNode { next, next_of_prev } = node.read();
+        let LlistNode { next, prev, next_of_prev } = node.read();
 
         debug_assert!(next_of_prev > 0x1000 as _);
         *next_of_prev = next;
@@ -63,27 +67,116 @@ impl LlistNode {
         if let Some(next) = next {
             debug_assert!(next.as_ptr() > 0x1000 as _);
             (*next.as_ptr()).next_of_prev = next_of_prev;
+            (*next.as_ptr()).prev = prev;
         }
     }
 
-    /* /// Move `self` into a new location, leaving `self` as an isolated node.
+    /// Concatenates the list `[src_head, src_tail]` onto the end of the list whose current tail
+    /// is `dst_tail`, in O(1).
+    ///
+    /// `dst_tail_slot` is the slot that terminates the destination list, into which `src_head` is
+    /// linked: either
+    /// `LlistNode::next_ptr(dst_tail)` when `dst_tail` is `Some`, or the external head slot when
+    /// the destination is empty (`dst_tail` is `None`). Does nothing if the source list is empty.
+    ///
     /// # Safety
-    /// * `dest` must be `ptr::write`-able.
-    /// * `self` must be dereferencable and valid.
+    /// * `dst_tail_slot` must be dereferencable and valid, and consistent with `dst_tail`.
+    /// * `src_head` and `src_tail` must both be `None`, or both `Some` and describe the two ends
+    ///   of the same valid, detached list.
+    pub unsafe fn append(
+        dst_tail_slot: *mut Option<NonNull<LlistNode>>,
+        dst_tail: Option<NonNull<LlistNode>>,
+        src_head: Option<NonNull<LlistNode>>,
+        src_tail: Option<NonNull<LlistNode>>,
+    ) {
+        debug_assert!(dst_tail_slot > 0x1000 as _);
+
+        let Some(src_head) = src_head else { return };
+        debug_assert!(src_tail.is_some());
+
+        (*src_head.as_ptr()).prev = dst_tail;
+        (*src_head.as_ptr()).next_of_prev = dst_tail_slot;
+        *dst_tail_slot = Some(src_head);
+    }
+
+    /// Inserts the list `[list_head, list_tail]` immediately after `node`, in O(1). Does nothing
+    /// if `list_head` is `None`; if `list_tail` is `None`, `list_head` is treated as a
+    /// single-node list (`list_tail` defaults to `list_head`).
+    ///
+    /// # Safety
+    /// * `node` must be dereferencable and valid.
+    /// * `list_head` and `list_tail`, if both `Some`, must describe the two ends of the same
+    ///   valid, detached list.
+    pub unsafe fn splice_after(
+        node: *mut Self,
+        list_head: Option<NonNull<LlistNode>>,
+        list_tail: Option<NonNull<LlistNode>>,
+    ) {
+        debug_assert!(node > 0x1000 as _);
+
+        let Some(list_head) = list_head else { return };
+        let list_tail = list_tail.unwrap_or(list_head);
+
+        let next = (*node).next;
+
+        (*list_head.as_ptr()).prev = Some(NonNull::new_unchecked(node));
+        (*list_head.as_ptr()).next_of_prev = Self::next_ptr(node);
+        (*node).next = Some(list_head);
+
+        (*list_tail.as_ptr()).next = next;
+        if let Some(next) = next {
+            (*next.as_ptr()).prev = Some(list_tail);
+            (*next.as_ptr()).next_of_prev = Self::next_ptr(list_tail.as_ptr());
+        }
+    }
+
+    /// Move `src`'s linkage to `dst`, leaving `src` isolated (it should be considered invalid).
+    ///
+    /// # Safety
+    /// * `dst` must be `ptr::write`-able.
+    /// * `src` must be dereferencable and valid.
     pub unsafe fn mov(src: *mut Self, dst: *mut Self) {
         debug_assert!(src > 0x1000 as _);
         debug_assert!(dst > 0x1000 as _);
 
-        let src_node = src.read();
+        let mut src_node = src.read();
+
+        debug_assert!(src_node.next_of_prev > 0x1000 as _);
 
-        *src_node.next_of_prev = Some(NonNull::new_unchecked(dst));
+        if src_node.next_of_prev == Self::next_ptr(src) {
+            // Self-referential sentinel: the predecessor slot lives inside `src` itself, so it
+            // must be retargeted at `dst` rather than written through.
+            src_node.next_of_prev = Self::next_ptr(dst);
+        } else {
+            *src_node.next_of_prev = Some(NonNull::new_unchecked(dst));
+        }
 
         if let Some(next) = src_node.next {
-            (*next.as_ptr()).next_of_prev = Self::next_ptr(dst);
+            if next.as_ptr() == src {
+                // Self-referential sentinel: the sole node of a circular list points to
+                // itself, so the link must be retargeted at `dst` rather than mutated in place.
+                src_node.next = Some(NonNull::new_unchecked(dst));
+            } else {
+                (*next.as_ptr()).next_of_prev = Self::next_ptr(dst);
+                (*next.as_ptr()).prev = Some(NonNull::new_unchecked(dst));
+            }
+        }
+
+        if src_node.prev.is_some_and(|prev| prev.as_ptr() == src) {
+            src_node.prev = Some(NonNull::new_unchecked(dst));
         }
 
         dst.write(src_node);
-    } */
+    }
+
+    /// Creates a cursor over the linked list, positioned at the node (if any) referenced by
+    /// `slot`.
+    /// # Safety
+    /// `slot` must be dereferencable and valid, and must be the head pointer of a valid
+    /// `LlistNode` list or the `next` field of a node within one.
+    pub unsafe fn cursor_mut(slot: *mut Option<NonNull<LlistNode>>) -> CursorMut {
+        CursorMut::new(slot)
+    }
 
     /// Creates an iterator over the circular linked list, exclusive of
     /// the sentinel.
@@ -93,6 +186,46 @@ impl LlistNode {
     pub unsafe fn iter_mut(first: Option<NonNull<Self>>) -> IterMut {
         IterMut::new(first)
     }
+
+    /// Creates an iterator over the linked list starting at `first` that yields pointers to the typed
+    /// containers embedding each node, recovered via [`Linked::container_of`].
+    /// # Safety
+    /// Same requirements as [`LlistNode::iter_mut`], and every node reachable from `first` must
+    /// be embedded in a live `C` at `C::NODE_OFFSET`.
+    pub unsafe fn iter_mut_typed<C: Linked>(first: Option<NonNull<Self>>) -> LinkedIterMut<C> {
+        LinkedIterMut::new(first)
+    }
+}
+
+/// Types whose instances embed an [`LlistNode`] and can be recovered from a pointer to it.
+///
+/// # Safety
+/// `NODE_OFFSET` must be the exact byte offset of the embedded `LlistNode` field within `Self`,
+/// normally obtained via `core::mem::offset_of!(Self, node_field)`. An incorrect offset makes
+/// `container_of` and `node_of` unsound.
+pub(crate) unsafe trait Linked {
+    /// Byte offset of the embedded `LlistNode` field within `Self`.
+    const NODE_OFFSET: usize;
+
+    /// Recovers a pointer to the owning `Self` from a pointer to its embedded node.
+    /// # Safety
+    /// `node` must point at the `LlistNode` embedded in a live `Self` at `Self::NODE_OFFSET`.
+    unsafe fn container_of(node: *mut LlistNode) -> *mut Self
+    where
+        Self: Sized,
+    {
+        node.cast::<u8>().wrapping_sub(Self::NODE_OFFSET).cast::<Self>()
+    }
+
+    /// Recovers a pointer to the embedded node from a pointer to the owning `Self`.
+    /// # Safety
+    /// `container` must be dereferencable, with a valid embedded `LlistNode` at `Self::NODE_OFFSET`.
+    unsafe fn node_of(container: *mut Self) -> *mut LlistNode
+    where
+        Self: Sized,
+    {
+        container.cast::<u8>().wrapping_add(Self::NODE_OFFSET).cast::<LlistNode>()
+    }
 }
 
 /// An iterator over the circular linked list `LlistNode`s, excluding the 'head'.
@@ -100,12 +233,20 @@ impl LlistNode {
 /// This `struct` is created by `LlistNode::iter_mut`. See its documentation for more.
 #[derive(Debug, Clone, Copy)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub(crate) struct IterMut(Option<NonNull<LlistNode>>);
+pub(crate) struct IterMut {
+    front: Option<NonNull<LlistNode>>,
+    back: Option<NonNull<LlistNode>>,
+}
 
 impl IterMut {
     /// Create a new iterator over the linked list from `first`.
     pub unsafe fn new(first: Option<NonNull<LlistNode>>) -> Self {
-        Self(first)
+        let mut back = first;
+        while let Some(node) = back.and_then(|node| (*node.as_ptr()).next) {
+            back = Some(node);
+        }
+
+        Self { front: first, back }
     }
 }
 
@@ -113,12 +254,123 @@ impl Iterator for IterMut {
     type Item = NonNull<LlistNode>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let current = self.0?;
-        self.0 = unsafe { (*current.as_ptr()).next };
+        let current = self.front?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.front = unsafe { (*current.as_ptr()).next };
+        }
         Some(current)
     }
 }
 
+impl DoubleEndedIterator for IterMut {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let current = self.back?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.back = unsafe { (*current.as_ptr()).prev };
+        }
+        Some(current)
+    }
+}
+
+/// A typed variant of [`IterMut`] that yields pointers to the `C` containers embedding each
+/// node, via [`Linked::container_of`].
+///
+/// This `struct` is created by `LlistNode::iter_mut_typed`. See its documentation for more.
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub(crate) struct LinkedIterMut<C: Linked>(IterMut, core::marker::PhantomData<fn() -> C>);
+
+impl<C: Linked> LinkedIterMut<C> {
+    /// Create a new typed iterator over the linked list from `first`.
+    /// # Safety
+    /// Same as [`LlistNode::iter_mut_typed`].
+    pub unsafe fn new(first: Option<NonNull<LlistNode>>) -> Self {
+        Self(IterMut::new(first), core::marker::PhantomData)
+    }
+}
+
+impl<C: Linked> Iterator for LinkedIterMut<C> {
+    type Item = *mut C;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.0.next()?;
+        Some(unsafe { C::container_of(node.as_ptr()) })
+    }
+}
+
+/// A cursor over a `LlistNode` list that supports structural edits at the current position in
+/// O(1), unlike [`IterMut`] which only yields pointers.
+///
+/// This `struct` is created by `LlistNode::cursor_mut`. See its documentation for more.
+#[derive(Debug)]
+pub(crate) struct CursorMut {
+    current: Option<NonNull<LlistNode>>,
+    /// The slot that currently feeds `current`: `LlistNode::next_ptr` of the previous node, or
+    /// the external head slot if `current` is the first node.
+    current_slot: *mut Option<NonNull<LlistNode>>,
+}
+
+impl CursorMut {
+    /// Create a new cursor positioned at the node referenced by `slot`.
+    /// # Safety
+    /// `slot` must be dereferencable and valid, and must be the head pointer of a valid
+    /// `LlistNode` list or the `next` field of a node within one.
+    pub unsafe fn new(slot: *mut Option<NonNull<LlistNode>>) -> Self {
+        debug_assert!(slot > 0x1000 as _);
+        Self { current: *slot, current_slot: slot }
+    }
+
+    /// Returns the node the cursor currently sits on, or `None` if it has run off the end of
+    /// the list.
+    pub fn current(&self) -> Option<NonNull<LlistNode>> {
+        self.current
+    }
+
+    /// Returns the node following the current position, without moving the cursor.
+    pub fn peek_next(&self) -> Option<NonNull<LlistNode>> {
+        self.current.and_then(|current| unsafe { (*current.as_ptr()).next })
+    }
+
+    /// Advances the cursor to the next node.
+    pub fn move_next(&mut self) {
+        if let Some(current) = self.current {
+            unsafe {
+                self.current_slot = LlistNode::next_ptr(current.as_ptr());
+                self.current = (*current.as_ptr()).next;
+            }
+        }
+    }
+
+    /// Removes the node at the current position in O(1), returning it and advancing the cursor
+    /// to the following element.
+    ///
+    /// # Safety
+    /// The cursor must currently be positioned on a node, i.e. `self.current()` is `Some`.
+    pub unsafe fn remove_current(&mut self) -> NonNull<LlistNode> {
+        let current = self.current.expect("cursor is not positioned on a node");
+        LlistNode::remove(current.as_ptr());
+        self.current = *self.current_slot;
+        current
+    }
+
+    /// Inserts `node` immediately after the current position.
+    ///
+    /// # Safety
+    /// * `node` must be `ptr::write`-able.
+    /// * The cursor must currently be positioned on a node.
+    pub unsafe fn insert_after(&mut self, node: *mut LlistNode) {
+        let current = self.current.expect("cursor is not positioned on a node");
+        let next = (*current.as_ptr()).next;
+        LlistNode::insert(node, LlistNode::next_ptr(current.as_ptr()), Some(current), next);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::ptr::{addr_of_mut, null_mut};
@@ -128,12 +380,17 @@ mod tests {
     #[test]
     fn dostuff() {
         unsafe {
-            let mut x: LlistNode = LlistNode { next: None, next_of_prev: null_mut() };
-            let mut y: LlistNode = LlistNode { next: None, next_of_prev: null_mut() };
-            let mut z: LlistNode = LlistNode { next: None, next_of_prev: null_mut() };
+            let mut x: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut y: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut z: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
 
-            LlistNode::insert(&mut y, LlistNode::next_ptr(&mut x), None);
-            LlistNode::insert(&mut z, LlistNode::next_ptr(&mut x), Some(NonNull::from(&mut y)));
+            LlistNode::insert(&mut y, LlistNode::next_ptr(&mut x), Some(NonNull::from(&mut x)), None);
+            LlistNode::insert(
+                &mut z,
+                LlistNode::next_ptr(&mut x),
+                Some(NonNull::from(&mut x)),
+                Some(NonNull::from(&mut y)),
+            );
 
             let mut iter = LlistNode::iter_mut(Some(NonNull::from(&mut x)));
             assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
@@ -152,7 +409,12 @@ mod tests {
             assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(y)));
             assert!(iter.next().is_none());
 
-            LlistNode::insert(&mut z, LlistNode::next_ptr(&mut x), Some(NonNull::from(&mut y)));
+            LlistNode::insert(
+                &mut z,
+                LlistNode::next_ptr(&mut x),
+                Some(NonNull::from(&mut x)),
+                Some(NonNull::from(&mut y)),
+            );
 
             let mut iter = LlistNode::iter_mut(Some(NonNull::from(&mut x)));
             assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
@@ -168,4 +430,247 @@ mod tests {
             assert!(iter.next().is_none());
         }
     }
+
+    #[test]
+    fn reverse_iteration() {
+        unsafe {
+            let mut x: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut y: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut z: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+
+            LlistNode::insert(&mut y, LlistNode::next_ptr(&mut x), Some(NonNull::from(&mut x)), None);
+            LlistNode::insert(
+                &mut z,
+                LlistNode::next_ptr(&mut x),
+                Some(NonNull::from(&mut x)),
+                Some(NonNull::from(&mut y)),
+            );
+
+            // forward: x, z, y
+            let mut iter = LlistNode::iter_mut(Some(NonNull::from(&mut x)));
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(y)));
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(z)));
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
+            assert!(iter.next_back().is_none());
+
+            // cursors meeting in the middle
+            let mut iter = LlistNode::iter_mut(Some(NonNull::from(&mut x)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(y)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(z)));
+            assert!(iter.next().is_none());
+            assert!(iter.next_back().is_none());
+        }
+    }
+
+    #[test]
+    fn append_and_splice() {
+        unsafe {
+            // dst: a -> b
+            let mut a: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut b: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut dst_head = None;
+            LlistNode::insert(&mut a, &mut dst_head, None, None);
+            LlistNode::insert(&mut b, LlistNode::next_ptr(&mut a), Some(NonNull::from(&mut a)), None);
+
+            // src: c -> d
+            let mut c: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut d: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut src_head = None;
+            LlistNode::insert(&mut c, &mut src_head, None, None);
+            LlistNode::insert(&mut d, LlistNode::next_ptr(&mut c), Some(NonNull::from(&mut c)), None);
+
+            // append src onto the end of dst: a -> b -> c -> d
+            LlistNode::append(
+                LlistNode::next_ptr(&mut b),
+                Some(NonNull::from(&mut b)),
+                Some(NonNull::from(&mut c)),
+                Some(NonNull::from(&mut d)),
+            );
+
+            assert!(c.prev.is_some_and(|n| n.as_ptr() == addr_of_mut!(b)));
+
+            let mut iter = LlistNode::iter_mut(dst_head);
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(a)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(b)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(c)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(d)));
+            assert!(iter.next().is_none());
+
+            // the join point also holds up walking in reverse
+            let mut iter = LlistNode::iter_mut(dst_head);
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(d)));
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(c)));
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(b)));
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(a)));
+            assert!(iter.next_back().is_none());
+
+            // appending an empty source is a no-op
+            LlistNode::append(LlistNode::next_ptr(&mut d), Some(NonNull::from(&mut d)), None, None);
+            let mut iter = LlistNode::iter_mut(dst_head);
+            assert!(iter.next_back().is_some_and(|n| n.as_ptr() == addr_of_mut!(d)));
+
+            // splice e in between a and b: a -> e -> b -> c -> d
+            let mut e: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            LlistNode::splice_after(&mut a, Some(NonNull::from(&mut e)), None);
+
+            let mut iter = LlistNode::iter_mut(dst_head);
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(a)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(e)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(b)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(c)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(d)));
+            assert!(iter.next().is_none());
+
+            // splicing an empty list is a no-op
+            LlistNode::splice_after(&mut a, None, None);
+            let mut iter = LlistNode::iter_mut(dst_head);
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(a)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(e)));
+        }
+    }
+
+    #[test]
+    fn mov_relocation() {
+        unsafe {
+            // head, middle, tail relocation on a 3-element list: x -> y -> z
+            let mut x: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut y: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut z: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut head = None;
+            LlistNode::insert(&mut x, &mut head, None, None);
+            LlistNode::insert(&mut y, LlistNode::next_ptr(&mut x), Some(NonNull::from(&mut x)), None);
+            LlistNode::insert(&mut z, LlistNode::next_ptr(&mut y), Some(NonNull::from(&mut y)), None);
+
+            // relocate the middle node
+            let mut y2: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            LlistNode::mov(&mut y, &mut y2);
+
+            let mut iter = LlistNode::iter_mut(head);
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(y2)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(z)));
+            assert!(iter.next().is_none());
+            assert!((*addr_of_mut!(z)).prev.is_some_and(|p| p.as_ptr() == addr_of_mut!(y2)));
+
+            // relocate the tail node
+            let mut z2: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            LlistNode::mov(&mut z, &mut z2);
+
+            let mut iter = LlistNode::iter_mut(head);
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(y2)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(z2)));
+            assert!(iter.next().is_none());
+
+            // relocate the head node; `mov` retargets the external `head` slot unaided
+            let mut x2: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            LlistNode::mov(&mut x, &mut x2);
+
+            let mut iter = LlistNode::iter_mut(head);
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(x2)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(y2)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(z2)));
+            assert!(iter.next().is_none());
+
+            // self-referential sentinel: a single-node circular list relocates cleanly
+            let mut s: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            s.next = Some(NonNull::from(&mut s));
+            s.prev = Some(NonNull::from(&mut s));
+            s.next_of_prev = LlistNode::next_ptr(&mut s);
+
+            let mut s2: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            LlistNode::mov(&mut s, &mut s2);
+
+            assert!(s2.next.is_some_and(|n| n.as_ptr() == addr_of_mut!(s2)));
+            assert!(s2.prev.is_some_and(|n| n.as_ptr() == addr_of_mut!(s2)));
+            assert!(s2.next_of_prev == LlistNode::next_ptr(&mut s2));
+        }
+    }
+
+    #[test]
+    fn cursor_mut() {
+        unsafe {
+            // x -> y -> z
+            let mut x: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut y: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut z: LlistNode = LlistNode { next: None, prev: None, next_of_prev: null_mut() };
+            let mut head = None;
+            LlistNode::insert(&mut x, &mut head, None, None);
+            LlistNode::insert(&mut y, LlistNode::next_ptr(&mut x), Some(NonNull::from(&mut x)), None);
+            LlistNode::insert(&mut z, LlistNode::next_ptr(&mut y), Some(NonNull::from(&mut y)), None);
+
+            let mut cursor = LlistNode::cursor_mut(&mut head);
+            assert!(cursor.current().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
+            assert!(cursor.peek_next().is_some_and(|n| n.as_ptr() == addr_of_mut!(y)));
+
+            cursor.move_next();
+            assert!(cursor.current().is_some_and(|n| n.as_ptr() == addr_of_mut!(y)));
+
+            // remove y mid-traversal: x -> z
+            let removed = cursor.remove_current();
+            assert!(removed.as_ptr() == addr_of_mut!(y));
+            assert!(cursor.current().is_some_and(|n| n.as_ptr() == addr_of_mut!(z)));
+
+            let mut iter = LlistNode::iter_mut(head);
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(z)));
+            assert!(iter.next().is_none());
+
+            // re-insert y after x: x -> y -> z
+            let mut cursor = LlistNode::cursor_mut(&mut head);
+            cursor.insert_after(&mut y);
+            assert!(cursor.current().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
+
+            let mut iter = LlistNode::iter_mut(head);
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(x)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(y)));
+            assert!(iter.next().is_some_and(|n| n.as_ptr() == addr_of_mut!(z)));
+            assert!(iter.next().is_none());
+
+            // walk off the end
+            let mut cursor = LlistNode::cursor_mut(&mut head);
+            cursor.move_next();
+            cursor.move_next();
+            cursor.move_next();
+            assert!(cursor.current().is_none());
+            assert!(cursor.peek_next().is_none());
+        }
+    }
+
+    struct Block {
+        _size: usize,
+        node: LlistNode,
+    }
+
+    unsafe impl Linked for Block {
+        const NODE_OFFSET: usize = core::mem::offset_of!(Block, node);
+    }
+
+    #[test]
+    fn linked_container_recovery() {
+        unsafe {
+            let mut a =
+                Block { _size: 1, node: LlistNode { next: None, prev: None, next_of_prev: null_mut() } };
+            let mut b =
+                Block { _size: 2, node: LlistNode { next: None, prev: None, next_of_prev: null_mut() } };
+
+            assert!(Block::container_of(Block::node_of(&mut a)) == addr_of_mut!(a));
+            assert!(Block::node_of(&mut a) == addr_of_mut!(a.node));
+
+            let mut head = None;
+            LlistNode::insert(&mut a.node, &mut head, None, None);
+            LlistNode::insert(
+                &mut b.node,
+                LlistNode::next_ptr(&mut a.node),
+                Some(NonNull::from(&mut a.node)),
+                None,
+            );
+
+            let mut iter = LlistNode::iter_mut_typed::<Block>(head);
+            assert!(iter.next().is_some_and(|c| c == addr_of_mut!(a)));
+            assert!(iter.next().is_some_and(|c| c == addr_of_mut!(b)));
+            assert!(iter.next().is_none());
+        }
+    }
 }